@@ -0,0 +1,113 @@
+//! `#[entry_point]`: generates a typed host-side argument-decoding preamble and a typed
+//! client-side call stub from a single annotated function signature, so a dependent contract
+//! can write `transfer_call::build_args(dest, amount)` instead of reconstructing the
+//! positional ABI (`get_arg(0)`, `get_arg(1)`, ad-hoc `InvalidArgument`/`MissingArgument`
+//! handling) by hand on both ends of the call.
+//!
+//! ```ignore
+//! #[entry_point]
+//! pub fn transfer(destination: PublicKey, amount: U512) {
+//!     // ... entry point body, `destination` and `amount` are already decoded ...
+//! }
+//! ```
+//!
+//! expands to the original function (host side, with a typed `get_arg` preamble inserted) plus
+//! a `transfer_call` module-level stub:
+//!
+//! ```ignore
+//! pub mod transfer_call {
+//!     pub fn build_args(destination: PublicKey, amount: U512) -> (PublicKey, U512) {
+//!         (destination, amount)
+//!     }
+//! }
+//! ```
+//!
+//! `build_args` is what a caller passes straight into `ExecuteRequestBuilder::standard`,
+//! so the positional ABI only has to be spelled out once, in the entry point's own signature.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, Type};
+
+pub(crate) fn expand(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+
+    let fn_name = &input.sig.ident;
+    let fn_vis = &input.vis;
+    let fn_block = &input.block;
+    let fn_attrs = &input.attrs;
+
+    let mut arg_names = Vec::new();
+    let mut arg_types = Vec::new();
+    for input in input.sig.inputs.iter() {
+        let pat_type = match input {
+            FnArg::Typed(pat_type) => pat_type,
+            FnArg::Receiver(_) => {
+                return syn::Error::new_spanned(input, "entry_point cannot take `self`")
+                    .to_compile_error()
+                    .into()
+            }
+        };
+        let pat_ident = match pat_type.pat.as_ref() {
+            Pat::Ident(pat_ident) => pat_ident,
+            other => {
+                return syn::Error::new_spanned(other, "entry_point arguments must be simple names")
+                    .to_compile_error()
+                    .into()
+            }
+        };
+        arg_names.push(pat_ident.ident.clone());
+        arg_types.push((*pat_type.ty).clone());
+    }
+
+    let decode_preamble = arg_names.iter().zip(arg_types.iter()).enumerate().map(
+        |(index, (name, ty))| decode_one_arg(index as u32, name, ty),
+    );
+
+    let call_module = format_ident!("{}_call", fn_name);
+
+    // A one-argument tuple still needs its trailing comma (`(T,)`), but a zero-argument one
+    // does not have a `(,)` tuple form at all — it's just `()`.
+    let (build_args_return_ty, build_args_body) = if arg_names.is_empty() {
+        (quote! { () }, quote! { () })
+    } else {
+        (
+            quote! { (#(#arg_types),*,) },
+            quote! { (#(#arg_names),*,) },
+        )
+    };
+
+    let expanded = quote! {
+        #(#fn_attrs)*
+        #[no_mangle]
+        #fn_vis extern "C" fn #fn_name() {
+            #(#decode_preamble)*
+            #fn_block
+        }
+
+        /// Typed client-side stub for calling this entry point: builds the positional argument
+        /// tuple expected by `ExecuteRequestBuilder`, so the ABI only needs to be written once.
+        #fn_vis mod #call_module {
+            use super::*;
+
+            pub fn build_args(#(#arg_names: #arg_types),*) -> #build_args_return_ty {
+                #build_args_body
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generates the same typed `get_arg`/`revert` preamble contract authors already write by
+/// hand (see `transfer_purse_to_account_stored`), against whatever `get_arg`/`revert`/`Error`
+/// the annotated function's crate already has in scope.
+fn decode_one_arg(index: u32, name: &syn::Ident, ty: &Type) -> proc_macro2::TokenStream {
+    quote! {
+        let #name: #ty = match get_arg(#index) {
+            ::core::option::Option::Some(::core::result::Result::Ok(value)) => value,
+            ::core::option::Option::Some(::core::result::Result::Err(_)) => revert(Error::InvalidArgument.into()),
+            ::core::option::Option::None => revert(Error::MissingArgument.into()),
+        };
+    }
+}
@@ -0,0 +1,119 @@
+//! Derive macro turning an error enum into a checked, collision-free, self-documenting
+//! revert error space, instead of the bare magic numbers (`revert(103)`) scattered across
+//! contracts.
+//!
+//! `#[derive(ContractError)]` assigns each variant a stable `u32` discriminant (in
+//! declaration order, starting at `0`, unless overridden with `#[error(code = N)]`),
+//! generates `From<MyError> for u32` for use with `revert`, and a reverse `try_from(code)`
+//! plus a human-readable message table so host-side test helpers can decode a contract's own
+//! error space instead of matching on substrings.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+mod entry_point;
+
+#[proc_macro_attribute]
+pub fn entry_point(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    entry_point::expand(item)
+}
+
+#[proc_macro_derive(ContractError, attributes(error))]
+pub fn derive_contract_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return syn::Error::new_spanned(&input, "ContractError can only be derived for enums")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut next_code: u32 = 0;
+    let mut codes = Vec::with_capacity(variants.len());
+    let mut seen_codes = Vec::with_capacity(variants.len());
+
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new_spanned(variant, "ContractError variants must be unit variants")
+                .to_compile_error()
+                .into();
+        }
+
+        let code = explicit_code(variant).unwrap_or(next_code);
+        if seen_codes.contains(&code) {
+            return syn::Error::new_spanned(
+                variant,
+                format!("duplicate error code {} (codes must be collision-free)", code),
+            )
+            .to_compile_error()
+            .into();
+        }
+        seen_codes.push(code);
+        next_code = code + 1;
+        codes.push(code);
+    }
+
+    let variant_idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+    let variant_strs: Vec<_> = variant_idents.iter().map(|ident| ident.to_string()).collect();
+
+    let expanded = quote! {
+        impl ::core::convert::From<#name> for u32 {
+            fn from(error: #name) -> u32 {
+                match error {
+                    #( #name::#variant_idents => #codes, )*
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<u32> for #name {
+            type Error = ();
+
+            fn try_from(code: u32) -> ::core::result::Result<#name, ()> {
+                match code {
+                    #( #codes => ::core::result::Result::Ok(#name::#variant_idents), )*
+                    _ => ::core::result::Result::Err(()),
+                }
+            }
+        }
+
+        impl #name {
+            /// A human-readable message for this variant, keyed the same way its wire code is,
+            /// so host-side test helpers can decode a contract's error space without matching
+            /// on substrings of a `Debug` string.
+            pub fn message(&self) -> &'static str {
+                match self {
+                    #( #name::#variant_idents => #variant_strs, )*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn explicit_code(variant: &syn::Variant) -> Option<u32> {
+    for attr in &variant.attrs {
+        if !attr.path.is_ident("error") {
+            continue;
+        }
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(name_value)) = nested {
+                    if name_value.path.is_ident("code") {
+                        if let Lit::Int(value) = name_value.lit {
+                            return value.base10_parse::<u32>().ok();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+/// Errors produced while encoding or decoding the hand-rolled bytesrepr wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The input ended before a value of the expected size could be read.
+    EarlyEndOfStream,
+    /// The input contained a tag or length that doesn't correspond to a valid value.
+    FormattingError,
+}
+
+/// Encodes a value into the bytesrepr wire format.
+pub trait ToBytes {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error>;
+}
+
+/// Decodes a value of this type from the front of a byte slice, returning the value together
+/// with the remaining, unconsumed bytes.
+pub trait FromBytes: Sized {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error>;
+}
+
+impl ToBytes for u8 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(alloc::vec![*self])
+    }
+}
+
+impl FromBytes for u8 {
+    fn from_bytes(bytes: &[u8]) -> Result<(u8, &[u8]), Error> {
+        match bytes.split_first() {
+            Some((&byte, rest)) => Ok((byte, rest)),
+            None => Err(Error::EarlyEndOfStream),
+        }
+    }
+}
+
+impl ToBytes for u32 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_le_bytes().to_vec())
+    }
+}
+
+impl FromBytes for u32 {
+    fn from_bytes(bytes: &[u8]) -> Result<(u32, &[u8]), Error> {
+        if bytes.len() < 4 {
+            return Err(Error::EarlyEndOfStream);
+        }
+        let (value_bytes, rest) = bytes.split_at(4);
+        let mut array = [0u8; 4];
+        array.copy_from_slice(value_bytes);
+        Ok((u32::from_le_bytes(array), rest))
+    }
+}
+
+impl ToBytes for [u8; 32] {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_vec())
+    }
+}
+
+impl FromBytes for [u8; 32] {
+    fn from_bytes(bytes: &[u8]) -> Result<([u8; 32], &[u8]), Error> {
+        if bytes.len() < 32 {
+            return Err(Error::EarlyEndOfStream);
+        }
+        let (value_bytes, rest) = bytes.split_at(32);
+        let mut array = [0u8; 32];
+        array.copy_from_slice(value_bytes);
+        Ok((array, rest))
+    }
+}
@@ -0,0 +1,680 @@
+//! A `serde` data format whose wire layout is byte-for-byte identical to the one produced by
+//! the hand-rolled `ToBytes`/`FromBytes` impls in `bytesrepr`: little-endian fixed-width
+//! integers, a little-endian `u32` length prefix ahead of every sequence/map/string, and a
+//! leading tag byte for `Option` and enum variants.
+//!
+//! This lets any `#[derive(Serialize, Deserialize)]` type be put on the Casper wire format
+//! without writing byte code by hand, while staying a drop-in replacement for existing
+//! `ToBytes`/`FromBytes` consumers.
+//!
+//! Note for `no_std` contracts: this module must never name `serde::de::Unexpected`. Its
+//! `Float(f64)` variant pulls in an `f64` `Display` impl that lowers to an `f64.load`
+//! instruction, which strict wasm validators reject. Type-mismatch errors are reported
+//! through `Error` instead.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{de, de::IntoDeserializer, de::Visitor, ser, Deserialize, Serialize};
+
+/// Errors produced while encoding or decoding the bytesrepr-compatible serde format.
+#[derive(Debug)]
+pub enum Error {
+    /// A sequence, map, or string was serialized without a known length.
+    LengthRequired,
+    /// The input ended before a value of the expected size could be read.
+    Eof,
+    /// The input contained a string that was not valid UTF-8.
+    InvalidUtf8,
+    /// The input contained a bool byte that was neither `0` nor `1`.
+    InvalidBool(u8),
+    /// The input contained an `Option` tag byte that was neither `0` nor `1`.
+    InvalidOptionTag(u8),
+    /// The requested type does not match the data found at the current position.
+    InvalidType(&'static str),
+    /// A custom error raised by the type being (de)serialized.
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::LengthRequired => write!(f, "sequence, map or string length must be known up front"),
+            Error::Eof => write!(f, "unexpected end of input"),
+            Error::InvalidUtf8 => write!(f, "invalid utf-8"),
+            Error::InvalidBool(byte) => write!(f, "invalid bool byte: {}", byte),
+            Error::InvalidOptionTag(byte) => write!(f, "invalid option tag byte: {}", byte),
+            Error::InvalidType(expected) => write!(f, "expected {}", expected),
+            Error::Custom(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// Encodes `value` into the bytesrepr wire format.
+pub fn to_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut serializer = Serializer { output: Vec::new() };
+    value.serialize(&mut serializer)?;
+    Ok(serializer.output)
+}
+
+/// Decodes a value of type `T` from the front of `bytes`, returning the value together with
+/// the remaining, unconsumed bytes.
+pub fn from_bytes<'a, T: Deserialize<'a>>(bytes: &'a [u8]) -> Result<(T, &'a [u8]), Error> {
+    let mut deserializer = Deserializer { input: bytes };
+    let value = T::deserialize(&mut deserializer)?;
+    Ok((value, deserializer.input))
+}
+
+struct Serializer {
+    output: Vec<u8>,
+}
+
+macro_rules! serialize_fixed_width {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, value: $ty) -> Result<(), Error> {
+            self.output.extend_from_slice(&value.to_le_bytes());
+            Ok(())
+        }
+    };
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, value: bool) -> Result<(), Error> {
+        self.output.push(value as u8);
+        Ok(())
+    }
+
+    serialize_fixed_width!(serialize_i8, i8);
+    serialize_fixed_width!(serialize_i16, i16);
+    serialize_fixed_width!(serialize_i32, i32);
+    serialize_fixed_width!(serialize_i64, i64);
+    serialize_fixed_width!(serialize_u8, u8);
+    serialize_fixed_width!(serialize_u16, u16);
+    serialize_fixed_width!(serialize_u32, u32);
+    serialize_fixed_width!(serialize_u64, u64);
+    serialize_fixed_width!(serialize_f32, f32);
+    serialize_fixed_width!(serialize_f64, f64);
+
+    fn serialize_char(self, value: char) -> Result<(), Error> {
+        self.serialize_str(value.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), Error> {
+        self.serialize_bytes(value.as_bytes())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), Error> {
+        self.output
+            .extend_from_slice(&(value.len() as u32).to_le_bytes());
+        self.output.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.output.push(0);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        self.output.push(1);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        self.output.push(variant_index as u8);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.output.push(variant_index as u8);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let len = len.ok_or(Error::LengthRequired)?;
+        self.output.extend_from_slice(&(len as u32).to_le_bytes());
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.output.push(variant_index as u8);
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let len = len.ok_or(Error::LengthRequired)?;
+        self.output.extend_from_slice(&(len as u32).to_le_bytes());
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.output.push(variant_index as u8);
+        Ok(self)
+    }
+}
+
+impl<'a> ser::SerializeSeq for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTuple for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTupleVariant for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeMap for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStruct for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeStructVariant for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    fn take(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.input.len() < len {
+            return Err(Error::Eof);
+        }
+        let (front, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(front)
+    }
+
+    fn take_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        let mut array = [0u8; 4];
+        array.copy_from_slice(bytes);
+        Ok(u32::from_le_bytes(array))
+    }
+}
+
+macro_rules! deserialize_fixed_width {
+    ($method:ident, $visit_method:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            const WIDTH: usize = core::mem::size_of::<$ty>();
+            let bytes = self.take(WIDTH)?;
+            let mut array = [0u8; WIDTH];
+            array.copy_from_slice(bytes);
+            visitor.$visit_method(<$ty>::from_le_bytes(array))
+        }
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
+    type Error = Error;
+
+    // This format is not self-describing: a caller must always deserialize into a concrete
+    // type rather than `deserialize_any`.
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error::InvalidType("a concrete type (format is not self-describing)"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.take(1)?[0] {
+            0 => visitor.visit_bool(false),
+            1 => visitor.visit_bool(true),
+            byte => Err(Error::InvalidBool(byte)),
+        }
+    }
+
+    deserialize_fixed_width!(deserialize_i8, visit_i8, i8);
+    deserialize_fixed_width!(deserialize_i16, visit_i16, i16);
+    deserialize_fixed_width!(deserialize_i32, visit_i32, i32);
+    deserialize_fixed_width!(deserialize_i64, visit_i64, i64);
+    deserialize_fixed_width!(deserialize_u8, visit_u8, u8);
+    deserialize_fixed_width!(deserialize_u16, visit_u16, u16);
+    deserialize_fixed_width!(deserialize_u32, visit_u32, u32);
+    deserialize_fixed_width!(deserialize_u64, visit_u64, u64);
+    deserialize_fixed_width!(deserialize_f32, visit_f32, f32);
+    deserialize_fixed_width!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.take_u32()? as usize;
+        let bytes = self.take(len)?;
+        let s = core::str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(Error::InvalidType("a single char")),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.take_u32()? as usize;
+        let bytes = self.take(len)?;
+        let s = core::str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)?;
+        visitor.visit_borrowed_str(s)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.take_u32()? as usize;
+        let bytes = self.take(len)?;
+        visitor.visit_borrowed_bytes(bytes)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.take(1)?[0] {
+            0 => visitor.visit_none(),
+            1 => visitor.visit_some(self),
+            byte => Err(Error::InvalidOptionTag(byte)),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.take_u32()? as usize;
+        visitor.visit_seq(SeqAccess { de: self, remaining: len })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqAccess { de: self, remaining: len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqAccess { de: self, remaining: len })
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.take_u32()? as usize;
+        visitor.visit_map(SeqAccess { de: self, remaining: len })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqAccess { de: self, remaining: fields.len() })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(EnumAccess { de: self })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_u32(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error::InvalidType("a concrete type (format is not self-describing)"))
+    }
+}
+
+struct SeqAccess<'de, 'a> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for SeqAccess<'de, 'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, 'a> de::MapAccess<'de> for SeqAccess<'de, 'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+struct EnumAccess<'de, 'a> {
+    de: &'a mut Deserializer<'de>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'de, 'a> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let tag = self.de.take(1)?[0] as u32;
+        let value = seed.deserialize(tag.into_deserializer())?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for EnumAccess<'de, 'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqAccess { de: self.de, remaining: len })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(SeqAccess { de: self.de, remaining: fields.len() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytesrepr::ToBytes;
+    use crate::value::account::{ActionType, Weight};
+    use crate::value::{SemVer, U512};
+
+    fn reference_semver_bytes(version: &SemVer) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&version.major.to_le_bytes());
+        bytes.extend_from_slice(&version.minor.to_le_bytes());
+        bytes.extend_from_slice(&version.patch.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn should_encode_semver_like_bytesrepr() {
+        let version = SemVer::new(1, 2, 3);
+        assert_eq!(to_bytes(&version).unwrap(), reference_semver_bytes(&version));
+    }
+
+    #[test]
+    fn should_round_trip_semver() {
+        let version = SemVer::new(4, 5, 6);
+        let bytes = to_bytes(&version).unwrap();
+        let (decoded, remainder): (SemVer, _) = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, version);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_encode_option_with_leading_tag_byte() {
+        let some_version = Some(SemVer::V1_0_0);
+        let bytes = to_bytes(&some_version).unwrap();
+        assert_eq!(bytes[0], 1);
+        let none_version: Option<SemVer> = None;
+        assert_eq!(to_bytes(&none_version).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn should_round_trip_vec_with_u32_length_prefix() {
+        let values: Vec<u32> = vec![1, 2, 3];
+        let bytes = to_bytes(&values).unwrap();
+        assert_eq!(&bytes[0..4], &3u32.to_le_bytes());
+        let (decoded, remainder): (Vec<u32>, _) = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, values);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_encode_u512_like_bytesrepr() {
+        let amount = U512::from(123_456_789);
+        assert_eq!(to_bytes(&amount).unwrap(), amount.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn should_round_trip_u512() {
+        let amount = U512::from(42);
+        let bytes = to_bytes(&amount).unwrap();
+        let (decoded, remainder): (U512, _) = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, amount);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_encode_account_action_type_like_bytesrepr() {
+        assert_eq!(
+            to_bytes(&ActionType::Deployment).unwrap(),
+            ActionType::Deployment.to_bytes().unwrap()
+        );
+        assert_eq!(
+            to_bytes(&ActionType::KeyManagement).unwrap(),
+            ActionType::KeyManagement.to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_encode_account_weight_like_bytesrepr() {
+        let weight = Weight(42);
+        assert_eq!(to_bytes(&weight).unwrap(), weight.to_bytes().unwrap());
+    }
+}
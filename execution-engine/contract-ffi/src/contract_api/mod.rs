@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub mod host_io;
+
+use crate::bytesrepr::{FromBytes, ToBytes};
+use crate::key::Key;
+use crate::value::account::{PublicKey, PurseId};
+use crate::value::contract::ContractHash;
+use crate::value::U512;
+
+/// A contract's own argument/ABI errors. Other error subsystems (e.g. a contract's own
+/// `#[derive(ContractError)]` enum) are layered on top of this for application-specific
+/// reverts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidArgument,
+    MissingArgument,
+    Transfer,
+}
+
+impl From<Error> for u32 {
+    fn from(error: Error) -> u32 {
+        match error {
+            Error::InvalidArgument => 1,
+            Error::MissingArgument => 2,
+            Error::Transfer => 3,
+        }
+    }
+}
+
+/// A typed, unforgeable reference to a value of type `T` stored in global state.
+pub struct TURef<T> {
+    key: Key,
+    _marker: PhantomData<T>,
+}
+
+impl<T> From<TURef<T>> for Key {
+    fn from(turef: TURef<T>) -> Key {
+        turef.key
+    }
+}
+
+// TODO(blocking): `ext_ffi` is a stand-in, not a finished host binding. Every function here
+// panics unconditionally, so `RealIo` cannot actually execute a contract on the real host yet
+// — only `MockIo`-driven native tests are currently functional. Wiring these to the real wasm
+// host import table (raw pointer/length `extern "C"` imports, not these `alloc`-friendly typed
+// signatures) is tracked as follow-up work and must land before `RealIo` is relied on for
+// anything beyond compiling.
+mod ext_ffi {
+    use alloc::vec::Vec;
+
+    use crate::key::Key;
+    use crate::value::account::{PublicKey, PurseId};
+    use crate::value::U512;
+
+    pub fn get_arg(_index: u32) -> Option<Vec<u8>> {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn put_key(_name: &str, _key: Key) {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn get_key(_name: &str) -> Option<Key> {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn read(_key: Key) -> Option<Vec<u8>> {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn write(_key: Key, _value: Vec<u8>) {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn new_uref(_value: Vec<u8>) -> Key {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn main_purse() -> PurseId {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn transfer_from_purse_to_account(
+        _source: PurseId,
+        _target: PublicKey,
+        _amount: U512,
+    ) -> Result<(), super::Error> {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn get_balance(_purse: PurseId) -> Option<U512> {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+
+    pub fn revert(_code: u32) -> ! {
+        unimplemented!("TODO: not yet bound to the host FFI")
+    }
+}
+
+/// Reads the `i`th argument passed to the current entry point as raw wire bytes, with no
+/// attempt to decode it. This is the primitive `get_arg::<T>` and `HostIo::get_arg` build on.
+pub fn get_arg_untyped(i: u32) -> Option<Vec<u8>> {
+    ext_ffi::get_arg(i)
+}
+
+/// Reads and decodes the `i`th argument passed to the current entry point.
+pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, Error>> {
+    get_arg_untyped(i).map(|bytes| {
+        T::from_bytes(&bytes)
+            .map(|(value, _)| value)
+            .map_err(|_| Error::InvalidArgument)
+    })
+}
+
+pub fn put_key(name: &str, key: &Key) {
+    ext_ffi::put_key(name, *key)
+}
+
+pub fn get_key(name: &str) -> Option<Key> {
+    ext_ffi::get_key(name)
+}
+
+pub fn read_untyped(key: &Key) -> Option<Vec<u8>> {
+    ext_ffi::read(*key)
+}
+
+pub fn write_untyped(key: &Key, value: Vec<u8>) {
+    ext_ffi::write(*key, value)
+}
+
+/// Creates a new unforgeable reference wrapping the raw, already-encoded `value`, with no
+/// further serialization. This is the primitive `new_turef::<T>` and `HostIo::new_turef` build
+/// on.
+pub fn new_turef_untyped(value: Vec<u8>) -> Key {
+    ext_ffi::new_uref(value)
+}
+
+pub fn new_turef<T: ToBytes>(value: T) -> TURef<T> {
+    let bytes = value.to_bytes().unwrap_or_else(|_| revert(Error::InvalidArgument.into()));
+    TURef {
+        key: new_turef_untyped(bytes),
+        _marker: PhantomData,
+    }
+}
+
+/// Registers a named entry point (together with the named keys visible to it) as a stored
+/// contract and returns its address.
+///
+/// TODO(blocking): not yet bound to the host FFI — see the note on `ext_ffi` above.
+pub fn fn_by_name(
+    _name: &str,
+    _named_keys: alloc::collections::BTreeMap<alloc::string::String, Key>,
+) -> ContractHash {
+    unimplemented!("TODO: not yet bound to the host FFI")
+}
+
+pub fn main_purse() -> PurseId {
+    ext_ffi::main_purse()
+}
+
+pub fn transfer_from_purse_to_account(
+    source: PurseId,
+    target: PublicKey,
+    amount: U512,
+) -> Result<(), Error> {
+    ext_ffi::transfer_from_purse_to_account(source, target, amount)
+}
+
+pub fn get_balance(purse: PurseId) -> Option<U512> {
+    ext_ffi::get_balance(purse)
+}
+
+pub fn revert(code: u32) -> ! {
+    ext_ffi::revert(code)
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::uref::URef;
+use crate::value::account::PublicKey;
+
+/// A location in global state: either an account, a stored contract, or a `URef`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Key {
+    Account(PublicKey),
+    Hash([u8; 32]),
+    URef(URef),
+}
+
+impl From<URef> for Key {
+    fn from(uref: URef) -> Key {
+        Key::URef(uref)
+    }
+}
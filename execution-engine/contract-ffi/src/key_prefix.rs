@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+
+use crate::bytesrepr::{Error as BytesreprError, FromBytes, ToBytes};
+use crate::uref::URef;
+
+/// A structured prefix over the logical groupings `contract_api`'s `put_key`/`get_key` don't
+/// expose a way to enumerate: every named key belonging to an entity, or every message/result a
+/// contract has emitted (like the repeated `transfer_result` URefs `transfer_purse_to_account`
+/// writes on each call), optionally narrowed to one topic.
+///
+/// Each variant round-trips through `ToBytes`/`FromBytes` the same way every other key-like
+/// value in this crate does, so it can itself be used as a store key or sent across the wire.
+/// [`HostIo::query_by_prefix`](crate::contract_api::host_io::HostIo::query_by_prefix) uses
+/// [`KeyPrefix::matches`] to decide which recorded entries a given prefix enumerates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyPrefix {
+    /// Every named key belonging to `entity`.
+    NamedKeysByEntity(URef),
+    /// Every message or result `entity` has emitted.
+    MessagesByEntity(URef),
+    /// Every message or result `entity` has emitted under `topic`.
+    MessagesByEntityAndTopic(URef, [u8; 32]),
+}
+
+const NAMED_KEYS_BY_ENTITY_TAG: u8 = 0;
+const MESSAGES_BY_ENTITY_TAG: u8 = 1;
+const MESSAGES_BY_ENTITY_AND_TOPIC_TAG: u8 = 2;
+
+impl ToBytes for KeyPrefix {
+    fn to_bytes(&self) -> Result<Vec<u8>, BytesreprError> {
+        let mut result = Vec::new();
+        match self {
+            KeyPrefix::NamedKeysByEntity(entity) => {
+                result.push(NAMED_KEYS_BY_ENTITY_TAG);
+                result.extend(entity.to_bytes()?);
+            }
+            KeyPrefix::MessagesByEntity(entity) => {
+                result.push(MESSAGES_BY_ENTITY_TAG);
+                result.extend(entity.to_bytes()?);
+            }
+            KeyPrefix::MessagesByEntityAndTopic(entity, topic) => {
+                result.push(MESSAGES_BY_ENTITY_AND_TOPIC_TAG);
+                result.extend(entity.to_bytes()?);
+                result.extend(topic.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for KeyPrefix {
+    fn from_bytes(bytes: &[u8]) -> Result<(KeyPrefix, &[u8]), BytesreprError> {
+        let (tag, rest): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            NAMED_KEYS_BY_ENTITY_TAG => {
+                let (entity, rest) = URef::from_bytes(rest)?;
+                Ok((KeyPrefix::NamedKeysByEntity(entity), rest))
+            }
+            MESSAGES_BY_ENTITY_TAG => {
+                let (entity, rest) = URef::from_bytes(rest)?;
+                Ok((KeyPrefix::MessagesByEntity(entity), rest))
+            }
+            MESSAGES_BY_ENTITY_AND_TOPIC_TAG => {
+                let (entity, rest) = URef::from_bytes(rest)?;
+                let (topic, rest) = <[u8; 32]>::from_bytes(rest)?;
+                Ok((KeyPrefix::MessagesByEntityAndTopic(entity, topic), rest))
+            }
+            _ => Err(BytesreprError::FormattingError),
+        }
+    }
+}
+
+impl KeyPrefix {
+    /// Whether `bucket` (the prefix an entry was recorded under) is enumerated by `self` (the
+    /// prefix a caller is querying for). `MessagesByEntity` is a wider grouping than any single
+    /// `MessagesByEntityAndTopic` of the same entity, so it also matches those; every other pair
+    /// of variants only matches itself.
+    pub fn matches(&self, bucket: &KeyPrefix) -> bool {
+        match (self, bucket) {
+            (KeyPrefix::NamedKeysByEntity(a), KeyPrefix::NamedKeysByEntity(b)) => a == b,
+            (KeyPrefix::MessagesByEntity(a), KeyPrefix::MessagesByEntity(b)) => a == b,
+            (KeyPrefix::MessagesByEntity(a), KeyPrefix::MessagesByEntityAndTopic(b, _)) => a == b,
+            (
+                KeyPrefix::MessagesByEntityAndTopic(a, topic_a),
+                KeyPrefix::MessagesByEntityAndTopic(b, topic_b),
+            ) => a == b && topic_a == topic_b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uref(byte: u8) -> URef {
+        URef::new([byte; 32], crate::uref::AccessRights::READ)
+    }
+
+    #[test]
+    fn should_round_trip_named_keys_by_entity() {
+        let prefix = KeyPrefix::NamedKeysByEntity(uref(7));
+        let bytes = prefix.to_bytes().unwrap();
+        let (decoded, remainder) = KeyPrefix::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, prefix);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_round_trip_messages_by_entity_and_topic() {
+        let prefix = KeyPrefix::MessagesByEntityAndTopic(uref(1), [9u8; 32]);
+        let bytes = prefix.to_bytes().unwrap();
+        let (decoded, remainder) = KeyPrefix::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, prefix);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_use_distinct_tags_per_variant() {
+        let named_keys = KeyPrefix::NamedKeysByEntity(uref(1)).to_bytes().unwrap();
+        let messages = KeyPrefix::MessagesByEntity(uref(1)).to_bytes().unwrap();
+        assert_ne!(named_keys[0], messages[0]);
+    }
+
+    #[test]
+    fn should_match_entity_level_messages_against_any_topic() {
+        let entity_level = KeyPrefix::MessagesByEntity(uref(1));
+        let topic_level = KeyPrefix::MessagesByEntityAndTopic(uref(1), [9u8; 32]);
+
+        assert!(entity_level.matches(&topic_level));
+        assert!(!topic_level.matches(&entity_level));
+    }
+
+    #[test]
+    fn should_not_match_across_entities_or_variants() {
+        let named_keys = KeyPrefix::NamedKeysByEntity(uref(1));
+        let messages = KeyPrefix::MessagesByEntity(uref(1));
+        let other_entity = KeyPrefix::MessagesByEntity(uref(2));
+
+        assert!(!named_keys.matches(&messages));
+        assert!(!messages.matches(&other_entity));
+    }
+}
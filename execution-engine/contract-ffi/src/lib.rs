@@ -0,0 +1,14 @@
+#![no_std]
+#![feature(cell_update)]
+
+#[macro_use]
+extern crate alloc;
+extern crate serde;
+
+pub mod bytesrepr;
+pub mod bytesrepr_serde;
+pub mod contract_api;
+pub mod key;
+pub mod key_prefix;
+pub mod uref;
+pub mod value;
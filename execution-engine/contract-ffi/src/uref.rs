@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Which operations a `URef` permits on the value it points to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AccessRights {
+    Read,
+    Write,
+    ReadWrite,
+    ReadAdd,
+    ReadAddWrite,
+}
+
+impl AccessRights {
+    pub const READ: AccessRights = AccessRights::Read;
+    pub const READ_ADD_WRITE: AccessRights = AccessRights::ReadAddWrite;
+}
+
+/// An unforgeable reference: a 32-byte address plus the access rights it was created with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct URef([u8; 32], AccessRights);
+
+impl URef {
+    pub fn new(address: [u8; 32], access_rights: AccessRights) -> URef {
+        URef(address, access_rights)
+    }
+
+    pub fn addr(&self) -> [u8; 32] {
+        self.0
+    }
+}
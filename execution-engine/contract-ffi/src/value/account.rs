@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bytesrepr::{Error as BytesreprError, FromBytes, ToBytes};
+use crate::uref::URef;
+
+/// Which class of account action a `Weight` threshold applies to, e.g. via
+/// `set_action_threshold`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionType {
+    Deployment,
+    KeyManagement,
+}
+
+impl ToBytes for ActionType {
+    fn to_bytes(&self) -> Result<Vec<u8>, BytesreprError> {
+        let tag: u8 = match self {
+            ActionType::Deployment => 0,
+            ActionType::KeyManagement => 1,
+        };
+        tag.to_bytes()
+    }
+}
+
+impl FromBytes for ActionType {
+    fn from_bytes(bytes: &[u8]) -> Result<(ActionType, &[u8]), BytesreprError> {
+        let (tag, rest): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            0 => Ok((ActionType::Deployment, rest)),
+            1 => Ok((ActionType::KeyManagement, rest)),
+            _ => Err(BytesreprError::FormattingError),
+        }
+    }
+}
+
+/// An account action's required key weight.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Weight(pub u32);
+
+impl ToBytes for Weight {
+    fn to_bytes(&self) -> Result<Vec<u8>, BytesreprError> {
+        self.0.to_bytes()
+    }
+}
+
+impl FromBytes for Weight {
+    fn from_bytes(bytes: &[u8]) -> Result<(Weight, &[u8]), BytesreprError> {
+        let (value, rest) = u32::from_bytes(bytes)?;
+        Ok((Weight(value), rest))
+    }
+}
+
+/// A purse identifier: the `URef` granting access to a purse's balance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PurseId(URef);
+
+impl PurseId {
+    pub fn new(uref: URef) -> PurseId {
+        PurseId(uref)
+    }
+}
+
+/// An ed25519 account public key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PublicKey([u8; 32]);
+
+impl PublicKey {
+    pub const fn ed25519_from(bytes: [u8; 32]) -> PublicKey {
+        PublicKey(bytes)
+    }
+}
+
+impl ToBytes for PublicKey {
+    fn to_bytes(&self) -> Result<Vec<u8>, BytesreprError> {
+        self.0.to_bytes()
+    }
+}
+
+impl FromBytes for PublicKey {
+    fn from_bytes(bytes: &[u8]) -> Result<(PublicKey, &[u8]), BytesreprError> {
+        let (value, rest) = <[u8; 32]>::from_bytes(bytes)?;
+        Ok((PublicKey(value), rest))
+    }
+}
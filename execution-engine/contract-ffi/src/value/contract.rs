@@ -0,0 +1,26 @@
+use alloc::vec::Vec;
+
+use crate::bytesrepr::{Error as BytesreprError, FromBytes, ToBytes};
+
+/// The address of a stored contract, as returned by `fn_by_name`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractHash([u8; 32]);
+
+impl ContractHash {
+    pub fn new(bytes: [u8; 32]) -> ContractHash {
+        ContractHash(bytes)
+    }
+}
+
+impl ToBytes for ContractHash {
+    fn to_bytes(&self) -> Result<Vec<u8>, BytesreprError> {
+        self.0.to_bytes()
+    }
+}
+
+impl FromBytes for ContractHash {
+    fn from_bytes(bytes: &[u8]) -> Result<(ContractHash, &[u8]), BytesreprError> {
+        let (value, rest) = <[u8; 32]>::from_bytes(bytes)?;
+        Ok((ContractHash(value), rest))
+    }
+}
@@ -0,0 +1,66 @@
+use alloc::collections::BTreeMap;
+
+use crate::value::contract::ContractHash;
+use crate::value::version_req::VersionReq;
+use crate::value::SemVer;
+
+/// A named collection of versions of the same logical contract, keyed by [`SemVer`] the same
+/// way a single version's entry points are keyed by name in a `Contract` (see `fn_by_name`).
+/// Lets a caller pin a dependency by version range instead of an exact `ContractHash`, so a
+/// contract package can gain new versions without breaking callers that pinned a range.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractPackage {
+    versions: BTreeMap<SemVer, ContractHash>,
+}
+
+impl ContractPackage {
+    /// Creates an empty contract package.
+    pub fn new() -> ContractPackage {
+        ContractPackage {
+            versions: BTreeMap::new(),
+        }
+    }
+
+    /// Adds or replaces the contract hash stored for `version`.
+    pub fn insert_version(&mut self, version: SemVer, contract_hash: ContractHash) {
+        self.versions.insert(version, contract_hash);
+    }
+
+    /// Returns the highest stored version that satisfies `req`, if any, along with its hash.
+    pub fn resolve(&self, req: &VersionReq) -> Option<(SemVer, ContractHash)> {
+        self.versions
+            .iter()
+            .rev()
+            .find(|(version, _)| req.matches(version))
+            .map(|(version, contract_hash)| (*version, *contract_hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_hash(byte: u8) -> ContractHash {
+        ContractHash::new([byte; 32])
+    }
+
+    #[test]
+    fn should_resolve_highest_matching_version() {
+        let mut package = ContractPackage::new();
+        package.insert_version(SemVer::new(1, 0, 0), contract_hash(1));
+        package.insert_version(SemVer::new(1, 2, 0), contract_hash(2));
+        package.insert_version(SemVer::new(2, 0, 0), contract_hash(3));
+
+        let req = VersionReq::parse("^1.0.0").unwrap();
+        assert_eq!(package.resolve(&req), Some((SemVer::new(1, 2, 0), contract_hash(2))));
+    }
+
+    #[test]
+    fn should_return_none_when_no_version_matches() {
+        let mut package = ContractPackage::new();
+        package.insert_version(SemVer::new(1, 0, 0), contract_hash(1));
+
+        let req = VersionReq::parse("^2.0.0").unwrap();
+        assert_eq!(package.resolve(&req), None);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod account;
+pub mod contract;
+pub mod contract_package;
+mod semver;
+mod u512;
+pub mod version_req;
+
+pub use semver::SemVer;
+pub use u512::U512;
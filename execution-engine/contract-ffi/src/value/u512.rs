@@ -0,0 +1,186 @@
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::bytesrepr::{Error as BytesreprError, FromBytes, ToBytes};
+
+/// A 512-bit unsigned integer, stored as 64 little-endian bytes — the type `transfer` moves
+/// between purses and reports back as `final_balance`.
+///
+/// Encoded the way `bytesrepr` encodes it: a single length byte giving the number of
+/// significant little-endian bytes (`0` for zero), followed by that many bytes. `Serialize`
+/// and `Deserialize` are hand-written rather than derived, since this compact, self-delimiting
+/// layout isn't the plain "field-by-field" shape `#[derive(Serialize, Deserialize)]` produces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct U512([u8; 64]);
+
+impl U512 {
+    pub fn zero() -> U512 {
+        U512([0u8; 64])
+    }
+
+    pub fn from(value: u64) -> U512 {
+        let mut bytes = [0u8; 64];
+        bytes[..8].copy_from_slice(&value.to_le_bytes());
+        U512(bytes)
+    }
+
+    fn significant_len(&self) -> usize {
+        let mut len = 64;
+        while len > 0 && self.0[len - 1] == 0 {
+            len -= 1;
+        }
+        len
+    }
+}
+
+impl Default for U512 {
+    fn default() -> U512 {
+        U512::zero()
+    }
+}
+
+// `[u8; 64]` stores the value little-endian, so the derived, index-0-first comparison would
+// compare least-significant bytes first — numerically backwards. Compare by significant length
+// first, then from the most-significant byte down.
+impl PartialOrd for U512 {
+    fn partial_cmp(&self, other: &U512) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U512 {
+    fn cmp(&self, other: &U512) -> core::cmp::Ordering {
+        let self_len = self.significant_len();
+        let other_len = other.significant_len();
+        self_len.cmp(&other_len).then_with(|| {
+            self.0[..self_len]
+                .iter()
+                .rev()
+                .cmp(other.0[..other_len].iter().rev())
+        })
+    }
+}
+
+impl core::ops::Sub for U512 {
+    type Output = U512;
+
+    fn sub(self, rhs: U512) -> U512 {
+        let mut result = [0u8; 64];
+        let mut borrow = 0i16;
+        for i in 0..64 {
+            let diff = self.0[i] as i16 - rhs.0[i] as i16 - borrow;
+            if diff < 0 {
+                result[i] = (diff + 256) as u8;
+                borrow = 1;
+            } else {
+                result[i] = diff as u8;
+                borrow = 0;
+            }
+        }
+        U512(result)
+    }
+}
+
+impl ToBytes for U512 {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, BytesreprError> {
+        let len = self.significant_len();
+        let mut result = alloc::vec::Vec::with_capacity(1 + len);
+        result.push(len as u8);
+        result.extend_from_slice(&self.0[..len]);
+        Ok(result)
+    }
+}
+
+impl FromBytes for U512 {
+    fn from_bytes(bytes: &[u8]) -> Result<(U512, &[u8]), BytesreprError> {
+        let (len, rest): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let len = len as usize;
+        if rest.len() < len || len > 64 {
+            return Err(BytesreprError::EarlyEndOfStream);
+        }
+        let (value_bytes, rest) = rest.split_at(len);
+        let mut bytes_array = [0u8; 64];
+        bytes_array[..len].copy_from_slice(value_bytes);
+        Ok((U512(bytes_array), rest))
+    }
+}
+
+impl Serialize for U512 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.significant_len();
+        let mut tup = serializer.serialize_tuple(1 + len)?;
+        tup.serialize_element(&(len as u8))?;
+        for byte in &self.0[..len] {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for U512 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<U512, D::Error> {
+        struct U512Visitor;
+
+        impl<'de> Visitor<'de> for U512Visitor {
+            type Value = U512;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a length-prefixed U512")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<U512, A::Error> {
+                let len: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::custom("missing U512 length byte"))?;
+                let mut bytes = [0u8; 64];
+                for byte in bytes.iter_mut().take(len as usize) {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::custom("truncated U512"))?;
+                }
+                Ok(U512(bytes))
+            }
+        }
+
+        // 65 = 1 length byte + the maximum 64 significant data bytes.
+        deserializer.deserialize_tuple(65, U512Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_via_bytesrepr() {
+        let value = U512::from(0x01_0203_0405_0607);
+        let bytes = value.to_bytes().unwrap();
+        let (decoded, remainder) = U512::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, value);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn should_encode_zero_with_no_trailing_bytes() {
+        assert_eq!(U512::zero().to_bytes().unwrap(), alloc::vec![0u8]);
+    }
+
+    #[test]
+    fn should_subtract() {
+        assert_eq!(U512::from(100) - U512::from(40), U512::from(60));
+    }
+
+    #[test]
+    fn should_order_numerically_not_byte_by_byte() {
+        assert!(U512::from(2) < U512::from(256));
+        assert!(U512::from(256) > U512::from(2));
+        assert!(U512::from(0x01_0000_0000) > U512::from(u32::max_value() as u64));
+        assert_eq!(U512::from(42).cmp(&U512::from(42)), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(U512::default(), U512::zero());
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::value::SemVer;
+
+/// A single comparator in a `VersionReq`, e.g. the `^1.2.3` in `^1.2.3, <1.5.0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: SemVer,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    /// `^major.minor.patch`: compatible-with, as defined by Cargo's caret requirements.
+    Caret,
+    /// `~major.minor.patch`: allows patch-level changes only.
+    Tilde,
+}
+
+impl Comparator {
+    fn matches(&self, version: &SemVer) -> bool {
+        match self.op {
+            Op::Exact => *version == self.version,
+            Op::Greater => *version > self.version,
+            Op::GreaterEq => *version >= self.version,
+            Op::Less => *version < self.version,
+            Op::LessEq => *version <= self.version,
+            Op::Tilde => {
+                version.major == self.version.major
+                    && version.minor == self.version.minor
+                    && version.patch >= self.version.patch
+            }
+            Op::Caret => {
+                let lower = self.version;
+                let upper = caret_upper_bound(&lower);
+                *version >= lower && *version < upper
+            }
+        }
+    }
+}
+
+/// The exclusive upper bound of a caret requirement, per Cargo's caret semantics: the next
+/// breaking change, where for `0.x` releases the minor (and for `0.0.x` the patch) version is
+/// treated as the breaking component.
+fn caret_upper_bound(version: &SemVer) -> SemVer {
+    if version.major > 0 {
+        SemVer::new(version.major + 1, 0, 0)
+    } else if version.minor > 0 {
+        SemVer::new(0, version.minor + 1, 0)
+    } else {
+        SemVer::new(0, 0, version.patch + 1)
+    }
+}
+
+/// A parsed version requirement, e.g. `^1.2.3` or `>=1.0.0, <2.0.0`, matched against a
+/// [`SemVer`] the same way a contract package pins a dependency's acceptable versions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+/// Error returned when a version requirement string cannot be parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VersionReqParseError;
+
+impl fmt::Display for VersionReqParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid version requirement")
+    }
+}
+
+impl VersionReq {
+    /// A requirement that matches any version.
+    pub fn any() -> VersionReq {
+        VersionReq {
+            comparators: Vec::new(),
+        }
+    }
+
+    /// Parses a comma-separated comparator set such as `"^1.2.3"` or `">=1.0.0, <2.0.0"`.
+    pub fn parse(text: &str) -> Result<VersionReq, VersionReqParseError> {
+        let text = text.trim();
+        if text == "*" {
+            return Ok(VersionReq::any());
+        }
+
+        let mut comparators = Vec::new();
+        for part in text.split(',') {
+            comparators.push(parse_comparator(part.trim())?);
+        }
+        Ok(VersionReq { comparators })
+    }
+
+    /// Returns `true` if `version` satisfies every comparator in this requirement.
+    pub fn matches(&self, version: &SemVer) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+fn parse_comparator(part: &str) -> Result<Comparator, VersionReqParseError> {
+    let (op, rest) = if let Some(rest) = part.strip_prefix(">=") {
+        (Op::GreaterEq, rest)
+    } else if let Some(rest) = part.strip_prefix("<=") {
+        (Op::LessEq, rest)
+    } else if let Some(rest) = part.strip_prefix('>') {
+        (Op::Greater, rest)
+    } else if let Some(rest) = part.strip_prefix('<') {
+        (Op::Less, rest)
+    } else if let Some(rest) = part.strip_prefix('^') {
+        (Op::Caret, rest)
+    } else if let Some(rest) = part.strip_prefix('~') {
+        (Op::Tilde, rest)
+    } else if let Some(rest) = part.strip_prefix('=') {
+        (Op::Exact, rest)
+    } else {
+        // A bare version defaults to caret matching, mirroring Cargo's default.
+        (Op::Caret, part)
+    };
+
+    let version = parse_semver(rest.trim())?;
+    Ok(Comparator { op, version })
+}
+
+fn parse_semver(text: &str) -> Result<SemVer, VersionReqParseError> {
+    let mut parts = text.split('.');
+    let major = parse_component(parts.next())?;
+    let minor = parse_component(parts.next())?;
+    let patch = parse_component(parts.next())?;
+    if parts.next().is_some() {
+        return Err(VersionReqParseError);
+    }
+    Ok(SemVer::new(major, minor, patch))
+}
+
+fn parse_component(part: Option<&str>) -> Result<u32, VersionReqParseError> {
+    part.ok_or(VersionReqParseError)?
+        .parse()
+        .map_err(|_| VersionReqParseError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_exact_requirement() {
+        let req = VersionReq::parse("=1.2.3").unwrap();
+        assert!(req.matches(&SemVer::new(1, 2, 3)));
+        assert!(!req.matches(&SemVer::new(1, 2, 4)));
+    }
+
+    #[test]
+    fn should_match_caret_requirement() {
+        let req = VersionReq::parse("^1.2.3").unwrap();
+        assert!(req.matches(&SemVer::new(1, 2, 3)));
+        assert!(req.matches(&SemVer::new(1, 9, 0)));
+        assert!(!req.matches(&SemVer::new(2, 0, 0)));
+        assert!(!req.matches(&SemVer::new(1, 2, 2)));
+    }
+
+    #[test]
+    fn should_lock_minor_for_zero_major_caret_requirement() {
+        let req = VersionReq::parse("^0.2.3").unwrap();
+        assert!(req.matches(&SemVer::new(0, 2, 9)));
+        assert!(!req.matches(&SemVer::new(0, 3, 0)));
+    }
+
+    #[test]
+    fn should_lock_patch_for_zero_minor_caret_requirement() {
+        let req = VersionReq::parse("^0.0.3").unwrap();
+        assert!(req.matches(&SemVer::new(0, 0, 3)));
+        assert!(!req.matches(&SemVer::new(0, 0, 4)));
+    }
+
+    #[test]
+    fn should_match_tilde_requirement() {
+        let req = VersionReq::parse("~1.2.3").unwrap();
+        assert!(req.matches(&SemVer::new(1, 2, 9)));
+        assert!(!req.matches(&SemVer::new(1, 3, 0)));
+    }
+
+    #[test]
+    fn should_match_range_requirement() {
+        let req = VersionReq::parse(">=1.0.0, <2.0.0").unwrap();
+        assert!(req.matches(&SemVer::new(1, 5, 0)));
+        assert!(!req.matches(&SemVer::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn should_match_wildcard_requirement() {
+        let req = VersionReq::parse("*").unwrap();
+        assert!(req.matches(&SemVer::new(0, 0, 0)));
+        assert!(req.matches(&SemVer::new(9, 9, 9)));
+    }
+
+    #[test]
+    fn should_reject_malformed_requirement() {
+        assert_eq!(VersionReq::parse("^1.2.x"), Err(VersionReqParseError));
+    }
+}
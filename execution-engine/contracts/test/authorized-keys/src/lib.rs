@@ -3,14 +3,28 @@
 
 extern crate alloc;
 extern crate cl_std;
+extern crate contract_ffi_derive;
+
 use cl_std::contract_api::{get_arg, revert, set_action_threshold};
 use cl_std::value::account::{ActionType, Weight};
+use contract_ffi_derive::ContractError;
+
+/// This contract's own revert error space, replacing the bare magic numbers
+/// (`revert(100)`/`revert(200)`) it used to raise.
+#[derive(ContractError)]
+enum Error {
+    #[error(code = 100)]
+    CouldNotSetKeyManagementThreshold,
+    #[error(code = 200)]
+    CouldNotSetDeploymentThreshold,
+}
 
 #[no_mangle]
 pub extern "C" fn call() {
     let deploy_threshold: Weight = get_arg(0);
     let key_management_threshold: Weight = get_arg(1);
     set_action_threshold(ActionType::KeyManagement, key_management_threshold)
-        .unwrap_or_else(|_| revert(100));
-    set_action_threshold(ActionType::Deployment, deploy_threshold).unwrap_or_else(|_| revert(200));
+        .unwrap_or_else(|_| revert(Error::CouldNotSetKeyManagementThreshold.into()));
+    set_action_threshold(ActionType::Deployment, deploy_threshold)
+        .unwrap_or_else(|_| revert(Error::CouldNotSetDeploymentThreshold.into()));
 }
@@ -0,0 +1,60 @@
+#![cfg_attr(not(test), no_std)]
+#![feature(cell_update)]
+
+#[macro_use]
+extern crate alloc;
+extern crate contract_ffi;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use contract_ffi::contract_api;
+use contract_ffi::contract_api::{get_arg, revert, Error};
+use contract_ffi::key::Key;
+use contract_ffi::value::account::PublicKey;
+use contract_ffi::value::U512;
+use contract_ffi_derive::entry_point;
+
+const ENTRY_POINT_DEMO_CONTRACT_NAME: &str = "entry_point_demo";
+
+/// The motivating case for `#[entry_point]`: a typed entry point taking arguments, with the
+/// `get_arg`/`revert` decoding preamble generated instead of hand-written.
+#[entry_point]
+pub fn greet(destination: PublicKey, amount: U512) {
+    let _ = (destination, amount);
+}
+
+/// A zero-argument entry point — the case whose generated `build_args` used to be the invalid
+/// `() -> (,)`.
+#[entry_point]
+pub fn ping() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let named_keys: BTreeMap<String, Key> = BTreeMap::new();
+    let contract = contract_api::fn_by_name("greet", named_keys);
+    let key = contract_api::new_turef(contract).into();
+    contract_api::put_key(ENTRY_POINT_DEMO_CONTRACT_NAME, &key);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_args_for_entry_point_with_arguments() {
+        let destination = PublicKey::ed25519_from([3u8; 32]);
+        let amount = U512::from(7);
+
+        let args = greet_call::build_args(destination, amount);
+
+        assert_eq!(args, (destination, amount));
+    }
+
+    #[test]
+    fn should_build_args_for_zero_argument_entry_point() {
+        let args = ping_call::build_args();
+
+        assert_eq!(args, ());
+    }
+}
@@ -1,4 +1,4 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![feature(cell_update)]
 
 #[macro_use]
@@ -8,43 +8,69 @@ extern crate contract_ffi;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
 
+use contract_ffi::contract_api::host_io::{HostIo, RealIo};
 use contract_ffi::contract_api::{self, Error};
 use contract_ffi::key::Key;
 use contract_ffi::value::account::{PublicKey, PurseId};
 use contract_ffi::value::U512;
+use contract_ffi_derive::ContractError;
 
 const TRANSFER_PURSE_TO_ACCOUNT_CONTRACT_NAME: &str = "transfer_purse_to_account";
 const TRANSFER_FUNCTION_NAME: &str = "transfer";
 const TRANSFER_RESULT_UREF_NAME: &str = "transfer_result";
 const MAIN_PURSE_FINAL_BALANCE_UREF_NAME: &str = "final_balance";
 
-#[no_mangle]
-pub extern "C" fn transfer() {
-    let source: PurseId = contract_api::main_purse();
-    let destination: PublicKey = match contract_api::get_arg(0) {
-        Some(Ok(data)) => data,
-        Some(Err(_)) => contract_api::revert(Error::InvalidArgument.into()),
-        None => contract_api::revert(Error::MissingArgument.into()),
+/// This contract's own revert error space, replacing the bare `revert(103)` it used to raise
+/// when the post-transfer balance couldn't be read or serialized.
+#[derive(ContractError)]
+enum TransferError {
+    #[error(code = 103)]
+    CouldNotGetOrSerializeFinalBalance,
+}
+
+/// The `transfer` entry point's logic, written against `HostIo` so it can run either on the
+/// real host (via `RealIo`) or natively against `MockIo` in a `cargo test`.
+fn transfer_with_io<IO: HostIo>(io: &mut IO, source: PurseId) {
+    let destination: PublicKey = match io.get_arg(0) {
+        Some(bytes) => match contract_ffi::bytesrepr::FromBytes::from_bytes(&bytes) {
+            Ok((value, _)) => value,
+            Err(_) => io.revert(Error::InvalidArgument.into()),
+        },
+        None => io.revert(Error::MissingArgument.into()),
     };
-    let amount: U512 = match contract_api::get_arg(1) {
-        Some(Ok(data)) => data,
-        Some(Err(_)) => contract_api::revert(Error::InvalidArgument.into()),
-        None => contract_api::revert(Error::MissingArgument.into()),
+    let amount: U512 = match io.get_arg(1) {
+        Some(bytes) => match contract_ffi::bytesrepr::FromBytes::from_bytes(&bytes) {
+            Ok((value, _)) => value,
+            Err(_) => io.revert(Error::InvalidArgument.into()),
+        },
+        None => io.revert(Error::MissingArgument.into()),
     };
 
-    let transfer_result = contract_api::transfer_from_purse_to_account(source, destination, amount);
+    let transfer_result = io.transfer_from_purse_to_account(source, destination, amount);
 
-    let final_balance =
-        contract_api::get_balance(source).unwrap_or_else(|| contract_api::revert(103));
+    let final_balance = io
+        .get_balance(source)
+        .unwrap_or_else(|| io.revert(TransferError::CouldNotGetOrSerializeFinalBalance.into()));
 
     let result = format!("{:?}", transfer_result);
+    let final_balance_bytes = contract_ffi::bytesrepr::ToBytes::to_bytes(&final_balance)
+        .unwrap_or_else(|_| io.revert(TransferError::CouldNotGetOrSerializeFinalBalance.into()));
+
+    let result_uref = io.new_turef(result.into_bytes());
+    io.put_key(TRANSFER_RESULT_UREF_NAME, result_uref);
 
-    let result_uref: Key = contract_api::new_turef(result).into();
-    contract_api::put_key(TRANSFER_RESULT_UREF_NAME, &result_uref);
-    contract_api::put_key(
-        MAIN_PURSE_FINAL_BALANCE_UREF_NAME,
-        &contract_api::new_turef(final_balance).into(),
-    );
+    let final_balance_uref = io.new_turef(final_balance_bytes);
+    io.put_key(MAIN_PURSE_FINAL_BALANCE_UREF_NAME, final_balance_uref);
+}
+
+// TODO(blocking): `RealIo` is not yet a working execution path (see its doc comment in
+// `contract_ffi::contract_api::host_io`) — this entry point only has functional coverage today
+// via `transfer_with_io`'s `MockIo`-driven tests below.
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let mut io = RealIo;
+    let source: PurseId = contract_api::main_purse();
+    transfer_with_io(&mut io, source);
 }
 
 #[no_mangle]
@@ -54,3 +80,77 @@ pub extern "C" fn call() {
     let key = contract_api::new_turef(contract).into();
     contract_api::put_key(TRANSFER_PURSE_TO_ACCOUNT_CONTRACT_NAME, &key);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use contract_ffi::bytesrepr::{FromBytes, ToBytes};
+    use contract_ffi::contract_api::host_io::MockIo;
+    use contract_ffi::key_prefix::KeyPrefix;
+    use contract_ffi::uref::{AccessRights, URef};
+
+    fn source_purse() -> PurseId {
+        PurseId::new(URef::new([1u8; 32], AccessRights::READ_ADD_WRITE))
+    }
+
+    #[test]
+    fn should_transfer_and_record_final_balance() {
+        let destination = PublicKey::ed25519_from([2u8; 32]);
+        let amount = U512::from(40);
+        let source = source_purse();
+
+        let mut io = MockIo::new(vec![
+            destination.to_bytes().unwrap(),
+            amount.to_bytes().unwrap(),
+        ]);
+        io.set_balance(source, U512::from(100));
+
+        transfer_with_io(&mut io, source);
+
+        let final_balance_key = *io
+            .named_keys()
+            .get(MAIN_PURSE_FINAL_BALANCE_UREF_NAME)
+            .expect("final balance uref should be recorded");
+        let final_balance_bytes = io
+            .read(final_balance_key)
+            .expect("final balance should be written to the store");
+        let (final_balance, _): (U512, _) = FromBytes::from_bytes(&final_balance_bytes).unwrap();
+
+        assert_eq!(final_balance, U512::from(60));
+        assert!(io.named_keys().contains_key(TRANSFER_RESULT_UREF_NAME));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_revert_when_destination_arg_missing() {
+        let mut io = MockIo::new(vec![]);
+        transfer_with_io(&mut io, source_purse());
+    }
+
+    #[test]
+    fn should_enumerate_transfer_result_urefs_by_prefix() {
+        let destination = PublicKey::ed25519_from([2u8; 32]);
+        let amount = U512::from(40);
+        let source = source_purse();
+        let entity = URef::new([9u8; 32], AccessRights::READ);
+
+        // An indexer or wallet enumerating this contract's output only needs to know the
+        // entity it ran as — not the `transfer_result`/`final_balance` names in advance.
+        let mut io = MockIo::new(vec![
+            destination.to_bytes().unwrap(),
+            amount.to_bytes().unwrap(),
+        ]);
+        io.set_current_entity(entity);
+        io.set_balance(source, U512::from(100));
+
+        transfer_with_io(&mut io, source);
+
+        let named_keys = io.query_by_prefix(&KeyPrefix::NamedKeysByEntity(entity));
+        let names: Vec<&Vec<u8>> = named_keys.iter().map(|(_, value)| value).collect();
+        assert_eq!(named_keys.len(), 2);
+        assert!(names.iter().any(|value| FromBytes::from_bytes(value)
+            .map(|(decoded, _): (U512, _)| decoded == U512::from(60))
+            .unwrap_or(false)));
+    }
+}
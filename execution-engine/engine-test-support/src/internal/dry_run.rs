@@ -0,0 +1,41 @@
+use crate::internal::{ExecuteRequest, ExecutionResult, InMemoryWasmTestBuilder};
+
+impl InMemoryWasmTestBuilder {
+    /// Executes `exec_request` against the current state and returns the resulting
+    /// `ExecutionResult` (gas consumed, produced transforms, and any error message) without
+    /// calling `commit()`, so this builder's post-state hash is left unchanged.
+    ///
+    /// This lets tooling and wallets estimate gas, detect a deploy that would revert (e.g. a
+    /// `ForgedReference`), and preview the named keys a deploy would produce before
+    /// broadcasting a real one.
+    pub fn dry_run(&mut self, exec_request: ExecuteRequest) -> ExecutionResult {
+        let response_index = self.exec_responses.len();
+        self.exec(exec_request);
+        self.get_exec_response(response_index)
+            .expect("dry_run should produce an execution result")
+            .to_owned()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::{ExecuteRequestBuilder, DEFAULT_GENESIS_CONFIG};
+    use crate::DEFAULT_ACCOUNT_ADDR;
+
+    const CONTRACT_TRANSFER: &str = "transfer_purse_to_account.wasm";
+
+    #[test]
+    fn should_not_commit_effects_of_a_dry_run() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+        let post_state_hash_before = builder.get_post_state_hash();
+
+        let exec_request =
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_TRANSFER, ()).build();
+        let _execution_result = builder.dry_run(exec_request);
+
+        assert_eq!(builder.get_post_state_hash(), post_state_hash_before);
+    }
+}
@@ -0,0 +1 @@
+pub mod dry_run;